@@ -1,9 +1,10 @@
 use parse::TokenParseError;
-use tokenize::TokenizeError;
+use tokenize::{Span, TokenizeError};
 use value::Value;
 use tokenize::tokenize;
 use parse::parse_tokens;
 
+mod number;
 mod value;
 mod tokenize;
 mod parse;
@@ -21,6 +22,68 @@ pub enum ParseError {
     ParseError(TokenParseError),
 }
 
+impl ParseError {
+    /// The span of input this error refers to.
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::TokenizeError(err) => err.span(),
+            ParseError::ParseError(err) => err.span(),
+        }
+    }
+
+    /// Renders a one-line excerpt of `source` around this error's location,
+    /// with a caret pointing at the offending byte, e.g. for `{"a": @}`:
+    ///
+    /// ```text
+    /// unexpected character '@' at line 1, column 7
+    /// {"a": @}
+    ///       ^
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        let (line_number, column, line) = line_and_column(source, self.span().start);
+
+        format!(
+            "{self} at line {line_number}, column {column}\n{line}\n{}^",
+            " ".repeat(column.saturating_sub(1)),
+        )
+    }
+}
+
+/// Finds the 1-indexed line number and column of a byte offset in `source`,
+/// along with the text of that line (excluding its trailing newline).
+fn line_and_column(source: &str, byte_offset: usize) -> (usize, usize, &str) {
+    let mut line_start = 0;
+
+    for (line_number, line) in source.split_inclusive('\n').enumerate() {
+        let line_end = line_start + line.len();
+        if byte_offset < line_end || line_end == source.len() {
+            let column = line[..byte_offset - line_start].chars().count() + 1;
+            return (line_number + 1, column, line.trim_end_matches('\n'));
+        }
+        line_start = line_end;
+    }
+
+    (1, source[..byte_offset].chars().count() + 1, source)
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::TokenizeError(err) => write!(f, "{err}"),
+            ParseError::ParseError(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::TokenizeError(err) => Some(err),
+            ParseError::ParseError(err) => Some(err),
+        }
+    }
+}
+
 impl From<TokenParseError> for ParseError {
     fn from(err: TokenParseError) -> Self {
         Self::ParseError(err)
@@ -31,4 +94,43 @@ impl From<TokenizeError> for ParseError {
     fn from(err: TokenizeError) -> Self {
         Self::TokenizeError(err)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn render_points_at_the_offending_character() {
+        let source = "[1, @]";
+        let err = parse(source).unwrap_err();
+
+        let rendered = err.render(source);
+
+        assert_eq!(
+            rendered,
+            "unexpected character '@' at line 1, column 5\n[1, @]\n    ^"
+        );
+    }
+
+    #[test]
+    fn render_counts_chars_not_bytes_before_multi_byte_utf8() {
+        let source = "[\"\u{03c0}\", @]";
+        let err = parse(source).unwrap_err();
+
+        let rendered = err.render(source);
+
+        assert_eq!(
+            rendered,
+            "unexpected character '@' at line 1, column 7\n[\"\u{03c0}\", @]\n      ^"
+        );
+    }
+
+    #[test]
+    fn display_matches_inner_error() {
+        let source = "[1, @]";
+        let err = parse(source).unwrap_err();
+
+        assert_eq!(err.to_string(), "unexpected character '@'");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,124 @@
+/// An exact decimal number: `sign * digits * 10^(-exponent)`.
+///
+/// Tokens and values carry this instead of `f64` so large integers (e.g.
+/// `12345678901234567890`) and long decimals (e.g. money values) round-trip
+/// exactly instead of losing precision in a float.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    negative: bool,
+    digits: i128,
+    exponent: i32,
+}
+
+impl Number {
+    pub fn new(negative: bool, digits: i128, exponent: i32) -> Self {
+        Self {
+            negative,
+            digits,
+            exponent,
+        }
+    }
+
+    /// Whether this number has no fractional remainder, i.e. `digits` is
+    /// evenly divisible by `10^exponent`.
+    pub fn is_integer(&self) -> bool {
+        match u32::try_from(self.exponent) {
+            Ok(exponent) => match 10i128.checked_pow(exponent) {
+                Some(scale) => self.digits % scale == 0,
+                None => false,
+            },
+            Err(_) => true,
+        }
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        let value = self.digits as f64 * 10f64.powi(-self.exponent);
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Returns the exact integer value, or `None` if this number has a
+    /// fractional remainder or overflows `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        if !self.is_integer() {
+            return None;
+        }
+
+        let value = if self.exponent <= 0 {
+            let scale = 10i128.checked_pow((-self.exponent) as u32)?;
+            self.digits.checked_mul(scale)?
+        } else {
+            let scale = 10i128.checked_pow(self.exponent as u32)?;
+            self.digits / scale
+        };
+
+        let value = if self.negative { -value } else { value };
+
+        i64::try_from(value).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Number;
+
+    #[test]
+    fn integer_is_integer() {
+        let number = Number::new(false, 123, 0);
+
+        assert!(number.is_integer());
+        assert_eq!(number.as_i64(), Some(123));
+        assert_eq!(number.as_f64(), 123.0);
+    }
+
+    #[test]
+    fn fractional_is_not_integer() {
+        let number = Number::new(false, 123, 2); // 1.23
+
+        assert!(!number.is_integer());
+        assert_eq!(number.as_i64(), None);
+        assert_eq!(number.as_f64(), 1.23);
+    }
+
+    #[test]
+    fn trailing_zero_fraction_is_integer() {
+        let number = Number::new(false, 12300, 2); // 123.00
+
+        assert!(number.is_integer());
+        assert_eq!(number.as_i64(), Some(123));
+    }
+
+    #[test]
+    fn negative_exponent_is_always_integer() {
+        let number = Number::new(false, 123, -2); // 123 * 10^2
+
+        assert!(number.is_integer());
+        assert_eq!(number.as_i64(), Some(12300));
+        assert_eq!(number.as_f64(), 12300.0);
+    }
+
+    #[test]
+    fn negative_number_folds_sign_into_as_f64() {
+        let number = Number::new(true, 123456, 1); // -123.456e+2
+
+        assert_eq!(number.as_f64(), -12345.6);
+    }
+
+    #[test]
+    fn negative_exponent_notation_folds_into_as_f64() {
+        let number = Number::new(true, 123456, 5); // -123.456e-2
+
+        assert_eq!(number.as_f64(), -1.23456);
+    }
+
+    #[test]
+    fn as_i64_overflow_returns_none() {
+        let number = Number::new(false, i128::from(i64::MAX) + 1, 0);
+
+        assert!(number.is_integer());
+        assert_eq!(number.as_i64(), None);
+    }
+}
@@ -1,11 +1,14 @@
 use std::collections::HashMap;
 
-use crate::{tokenize::Token, value::Value};
+use crate::{
+    tokenize::{Span, Token},
+    value::Value,
+};
 
 type ParseResult = Result<Value, TokenParseError>;
 
-pub fn parse_tokens(tokens: &[Token], index: &mut usize) -> ParseResult {
-    let token = &tokens[*index];
+pub fn parse_tokens(tokens: &[(Token, Span)], index: &mut usize) -> ParseResult {
+    let (token, span) = &tokens[*index];
     if matches!(
         token,
         Token::Null | Token::False | Token::True | Token::Number(_) | Token::String(_)
@@ -17,7 +20,7 @@ pub fn parse_tokens(tokens: &[Token], index: &mut usize) -> ParseResult {
         Token::False => Ok(Value::Boolean(false)),
         Token::True => Ok(Value::Boolean(true)),
         Token::Number(number) => Ok(Value::Number(*number)),
-        Token::String(string) => parse_string(string),
+        Token::String(string) => parse_string(string, *span),
         Token::LeftBracket => parse_array(tokens, index),
         Token::LeftBrace => parse_object(tokens, index),
 
@@ -25,7 +28,7 @@ pub fn parse_tokens(tokens: &[Token], index: &mut usize) -> ParseResult {
     }
 }
 
-fn parse_string(s: &str) -> ParseResult {
+fn parse_string(s: &str, span: Span) -> ParseResult {
     let mut output = String::new();
 
     let mut is_escaping = false;
@@ -44,14 +47,16 @@ fn parse_string(s: &str) -> ParseResult {
                 'u' => {
                     let mut sum = 0;
                     for i in 0..4 {
-                        let next_char = chars.next().ok_or(TokenParseError::UnfinishedEscape)?;
+                        let next_char = chars
+                            .next()
+                            .ok_or(TokenParseError::UnfinishedEscape(span))?;
                         let digit = next_char
                             .to_digit(16)
-                            .ok_or(TokenParseError::InvalidHexValue)?;
+                            .ok_or(TokenParseError::InvalidHexValue(span))?;
                         sum += (16u32).pow(3 - i) * digit;
                     }
                     let unescaped_char =
-                        char::from_u32(sum).ok_or(TokenParseError::InvalidCodePointValue)?;
+                        char::from_u32(sum).ok_or(TokenParseError::InvalidCodePointValue(span))?;
                     output.push(unescaped_char);
                 }
                 // any other character *may* be escaped, ex. `\q` just push that letter `q`
@@ -68,23 +73,23 @@ fn parse_string(s: &str) -> ParseResult {
     Ok(Value::String(output))
 }
 
-fn parse_array(tokens: &[Token], index: &mut usize) -> ParseResult {
+fn parse_array(tokens: &[(Token, Span)], index: &mut usize) -> ParseResult {
     let mut array = Vec::new();
 
     loop {
         *index += 1;
-        if tokens[*index] == Token::RightBracket {
+        if tokens[*index].0 == Token::RightBracket {
             break;
         }
 
         let value = parse_tokens(tokens, index)?;
         array.push(value);
 
-        let token = &tokens[*index];
+        let (token, span) = &tokens[*index];
         match token {
             Token::Comma => {}
             Token::RightBracket => break,
-            _ => return Err(TokenParseError::ExpectedComma),
+            _ => return Err(TokenParseError::ExpectedComma(*span)),
         }
     }
 
@@ -93,33 +98,34 @@ fn parse_array(tokens: &[Token], index: &mut usize) -> ParseResult {
     Ok(Value::Array(array))
 }
 
-fn parse_object(tokens: &[Token], index: &mut usize) -> ParseResult {
+fn parse_object(tokens: &[(Token, Span)], index: &mut usize) -> ParseResult {
     let mut object = HashMap::new();
 
     loop {
         *index += 1;
-        if tokens[*index] == Token::RightBrace {
+        if tokens[*index].0 == Token::RightBrace {
             break;
         }
 
-        if let Token::String(key) = &tokens[*index] {
+        if let Token::String(key) = &tokens[*index].0 {
             *index += 1;
-            let token = &tokens[*index];
+            let (token, colon_span) = &tokens[*index];
             if Token::Colon == *token {
                 *index += 1;
                 let value = parse_tokens(tokens, index)?;
                 object.insert(key.clone(), value);
 
                 match &tokens[*index] {
-                    Token::Comma => {}
-                    Token::RightBrace => break,
-                    _ => return Err(TokenParseError::ExpectedComma),
+                    (Token::Comma, _) => {}
+                    (Token::RightBrace, _) => break,
+                    (_, span) => return Err(TokenParseError::ExpectedComma(*span)),
                 }
             } else {
-                return Err(TokenParseError::ExpectedColon);
+                return Err(TokenParseError::ExpectedColon(*colon_span));
             }
         } else {
-            return Err(TokenParseError::ExpectedProperty);
+            let span = tokens[*index].1;
+            return Err(TokenParseError::ExpectedProperty(span));
         }
     }
 
@@ -130,29 +136,73 @@ fn parse_object(tokens: &[Token], index: &mut usize) -> ParseResult {
 #[derive(Debug, PartialEq)]
 pub enum TokenParseError {
     /// An escape sequence was started without 4 hexadecimal digits afterwards
-    UnfinishedEscape,
+    UnfinishedEscape(Span),
     /// A character in an escape sequence was not valid hexadecimal
-    InvalidHexValue,
+    InvalidHexValue(Span),
     /// Invalid unicode value
-    InvalidCodePointValue,
+    InvalidCodePointValue(Span),
 
-    ExpectedComma,
+    ExpectedComma(Span),
 
-    ExpectedColon,
+    ExpectedColon(Span),
 
-    ExpectedProperty,
+    ExpectedProperty(Span),
 }
 
+impl TokenParseError {
+    /// The span of input this error refers to.
+    pub fn span(&self) -> Span {
+        match self {
+            TokenParseError::UnfinishedEscape(span) => *span,
+            TokenParseError::InvalidHexValue(span) => *span,
+            TokenParseError::InvalidCodePointValue(span) => *span,
+            TokenParseError::ExpectedComma(span) => *span,
+            TokenParseError::ExpectedColon(span) => *span,
+            TokenParseError::ExpectedProperty(span) => *span,
+        }
+    }
+}
+
+impl std::fmt::Display for TokenParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenParseError::UnfinishedEscape(_) => {
+                write!(f, "unfinished escape sequence")
+            }
+            TokenParseError::InvalidHexValue(_) => {
+                write!(f, "invalid hexadecimal digit in escape sequence")
+            }
+            TokenParseError::InvalidCodePointValue(_) => {
+                write!(f, "escape sequence is not a valid unicode code point")
+            }
+            TokenParseError::ExpectedComma(_) => write!(f, "expected ','"),
+            TokenParseError::ExpectedColon(_) => write!(f, "expected ':' after object key"),
+            TokenParseError::ExpectedProperty(_) => {
+                write!(f, "expected a string property name")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokenParseError {}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use crate::tokenize::Token;
+    use crate::number::Number;
+    use crate::tokenize::{Span, Token};
     use crate::value::Value;
 
     use super::parse_tokens;
 
-    fn check(input: &[Token], expected: Value) {
+    const DUMMY_SPAN: Span = Span { start: 0, end: 0 };
+
+    fn token(token: Token) -> (Token, Span) {
+        (token, DUMMY_SPAN)
+    }
+
+    fn check(input: &[(Token, Span)], expected: Value) {
         let actual = parse_tokens(input, &mut 0).unwrap();
 
         assert_eq!(actual, expected);
@@ -160,7 +210,7 @@ mod tests {
 
     #[test]
     fn parses_null() {
-        let input = vec![Token::Null];
+        let input = vec![token(Token::Null)];
         let expected = Value::Null;
 
         check(&input, expected);
@@ -168,7 +218,7 @@ mod tests {
 
     #[test]
     fn parses_string_no_escapes() {
-        let input = vec![Token::String("hello world".into())];
+        let input = vec![token(Token::String("hello world".into()))];
         let expected = Value::String("hello world".into());
 
         check(&input, expected);
@@ -176,7 +226,7 @@ mod tests {
 
     #[test]
     fn parses_string_non_ascii() {
-        let input = vec![Token::string("ol√°_„Åì„Çì„Å´„Å°„ÅØ_‡§®‡§Æ‡§∏‡•ç‡§§‡•á_–ø—Ä–∏–≤—ñ—Ç")];
+        let input = vec![token(Token::string("ol√°_„Åì„Çì„Å´„Å°„ÅØ_‡§®‡§Æ‡§∏‡•ç‡§§‡•á_–ø—Ä–∏–≤—ñ—Ç"))];
         let expected = Value::String(String::from("ol√°_„Åì„Çì„Å´„Å°„ÅØ_‡§®‡§Æ‡§∏‡•ç‡§§‡•á_–ø—Ä–∏–≤—ñ—Ç"));
 
         check(&input, expected);
@@ -184,15 +234,15 @@ mod tests {
 
     #[test]
     fn parses_string_with_emoji() {
-        let input = vec![Token::string("hello üí© world")];
-        let expected = Value::String(String::from("hello üí© world"));
+        let input = vec![token(Token::string("hello üí© world"))];
+        let expected = Value::String(String::from("hello üí© world"));
 
         check(&input, expected);
     }
 
     #[test]
     fn parses_string_unescape_backslash() {
-        let input = vec![Token::String(r#"hello\\world"#.into())];
+        let input = vec![token(Token::String(r#"hello\\world"#.into()))];
         let expected = Value::String(r#"hello\world"#.into());
 
         check(&input, expected);
@@ -201,7 +251,11 @@ mod tests {
     #[test]
     fn parses_array_one_element() {
         // [true]
-        let input = vec![Token::LeftBracket, Token::True, Token::RightBracket];
+        let input = vec![
+            token(Token::LeftBracket),
+            token(Token::True),
+            token(Token::RightBracket),
+        ];
         let expected = Value::Array(vec![Value::Boolean(true)]);
 
         check(&input, expected);
@@ -211,13 +265,13 @@ mod tests {
     fn parses_array_two_elements() {
         // [null, 16]
         let input = vec![
-            Token::LeftBracket,
-            Token::Null,
-            Token::Comma,
-            Token::Number(16.0),
-            Token::RightBracket,
+            token(Token::LeftBracket),
+            token(Token::Null),
+            token(Token::Comma),
+            token(Token::Number(Number::new(false, 16, 0))),
+            token(Token::RightBracket),
         ];
-        let expected = Value::Array(vec![Value::Null, Value::Number(16.0)]);
+        let expected = Value::Array(vec![Value::Null, Value::Number(Number::new(false, 16, 0))]);
 
         check(&input, expected);
     }
@@ -225,7 +279,7 @@ mod tests {
     #[test]
     fn parses_empty_array() {
         // []
-        let input = vec![Token::LeftBracket, Token::RightBracket];
+        let input = vec![token(Token::LeftBracket), token(Token::RightBracket)];
         let expected = Value::Array(vec![]);
 
         check(&input, expected);
@@ -235,13 +289,13 @@ mod tests {
     fn parses_nested_array() {
         // [null, [null]]
         let input = vec![
-            Token::LeftBracket,
-            Token::Null,
-            Token::Comma,
-            Token::LeftBracket,
-            Token::Null,
-            Token::RightBracket,
-            Token::RightBracket,
+            token(Token::LeftBracket),
+            token(Token::Null),
+            token(Token::Comma),
+            token(Token::LeftBracket),
+            token(Token::Null),
+            token(Token::RightBracket),
+            token(Token::RightBracket),
         ];
         let expected = Value::Array(vec![Value::Null, Value::Array(vec![Value::Null])]);
 
@@ -252,11 +306,11 @@ mod tests {
     fn parses_object() {
         // { "a": true }
         let input = vec![
-            Token::LeftBrace,
-            Token::String("a".into()),
-            Token::Colon,
-            Token::True,
-            Token::RightBrace,
+            token(Token::LeftBrace),
+            token(Token::String("a".into())),
+            token(Token::Colon),
+            token(Token::True),
+            token(Token::RightBrace),
         ];
         let expected = Value::Object(HashMap::from([("a".into(), Value::Boolean(true))]));
 
@@ -267,15 +321,15 @@ mod tests {
     fn parses_object_with_nested_object() {
         // { "a": { "b": true } }
         let input = vec![
-            Token::LeftBrace,
-            Token::String("a".into()),
-            Token::Colon,
-            Token::LeftBrace,
-            Token::String("b".into()),
-            Token::Colon,
-            Token::True,
-            Token::RightBrace,
-            Token::RightBrace,
+            token(Token::LeftBrace),
+            token(Token::String("a".into())),
+            token(Token::Colon),
+            token(Token::LeftBrace),
+            token(Token::String("b".into())),
+            token(Token::Colon),
+            token(Token::True),
+            token(Token::RightBrace),
+            token(Token::RightBrace),
         ];
         let expected = Value::Object(HashMap::from([(
             "a".into(),
@@ -289,13 +343,13 @@ mod tests {
     fn parses_object_with_nested_array() {
         // { "a": [true] }
         let input = vec![
-            Token::LeftBrace,
-            Token::String("a".into()),
-            Token::Colon,
-            Token::LeftBracket,
-            Token::True,
-            Token::RightBracket,
-            Token::RightBrace,
+            token(Token::LeftBrace),
+            token(Token::String("a".into())),
+            token(Token::Colon),
+            token(Token::LeftBracket),
+            token(Token::True),
+            token(Token::RightBracket),
+            token(Token::RightBrace),
         ];
         let expected = Value::Object(HashMap::from([(
             "a".into(),
@@ -309,19 +363,19 @@ mod tests {
     fn parses_object_with_nested_array_and_object() {
         // { "a": [true, { "b": true }] }
         let input = vec![
-            Token::LeftBrace,
-            Token::String("a".into()),
-            Token::Colon,
-            Token::LeftBracket,
-            Token::True,
-            Token::Comma,
-            Token::LeftBrace,
-            Token::String("b".into()),
-            Token::Colon,
-            Token::True,
-            Token::RightBrace,
-            Token::RightBracket,
-            Token::RightBrace,
+            token(Token::LeftBrace),
+            token(Token::String("a".into())),
+            token(Token::Colon),
+            token(Token::LeftBracket),
+            token(Token::True),
+            token(Token::Comma),
+            token(Token::LeftBrace),
+            token(Token::String("b".into())),
+            token(Token::Colon),
+            token(Token::True),
+            token(Token::RightBrace),
+            token(Token::RightBracket),
+            token(Token::RightBrace),
         ];
         let expected = Value::Object(HashMap::from([(
             "a".into(),
@@ -338,13 +392,13 @@ mod tests {
     fn parses_array_with_object() {
         // [ { "a": true } ]
         let input = vec![
-            Token::LeftBracket,
-            Token::LeftBrace,
-            Token::String("a".into()),
-            Token::Colon,
-            Token::True,
-            Token::RightBrace,
-            Token::RightBracket,
+            token(Token::LeftBracket),
+            token(Token::LeftBrace),
+            token(Token::String("a".into())),
+            token(Token::Colon),
+            token(Token::True),
+            token(Token::RightBrace),
+            token(Token::RightBracket),
         ];
         let expected = Value::Array(vec![Value::Object(HashMap::from([(
             "a".into(),
@@ -1,4 +1,4 @@
-use std::num::ParseFloatError;
+use crate::number::Number;
 
 #[derive(Debug, PartialEq)]
 pub enum Token {
@@ -13,167 +13,294 @@ pub enum Token {
     False,
     True,
 
-    Number(f64),
+    Number(Number),
     String(String),
 }
 
+/// A byte-offset range `[start, end)` into the original input, identifying
+/// where a token or error came from.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum TokenizeError {
-    UnfinishedLiteralValue,
-    ParseNumberError(ParseFloatError),
-    UnclosedQuotes,
-    UnexpectedEof,
-    CharNotRecognized(char),
+    UnfinishedLiteralValue(Span),
+    InvalidNumber(Span),
+    UnclosedQuotes(Span),
+    UnexpectedEof(Span),
+    CharNotRecognized(char, Span),
 }
 
-pub fn tokenize(input: String) -> Result<Vec<Token>, TokenizeError> {
-    let chars: Vec<char> = input.chars().collect();
-    let mut index = 0;
+impl TokenizeError {
+    /// The span of input this error refers to.
+    pub fn span(&self) -> Span {
+        match self {
+            TokenizeError::UnfinishedLiteralValue(span) => *span,
+            TokenizeError::InvalidNumber(span) => *span,
+            TokenizeError::UnclosedQuotes(span) => *span,
+            TokenizeError::UnexpectedEof(span) => *span,
+            TokenizeError::CharNotRecognized(_, span) => *span,
+        }
+    }
+}
 
+impl std::fmt::Display for TokenizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenizeError::UnfinishedLiteralValue(_) => {
+                write!(f, "unfinished literal value")
+            }
+            TokenizeError::InvalidNumber(_) => write!(f, "invalid number"),
+            TokenizeError::UnclosedQuotes(_) => write!(f, "unclosed quotes"),
+            TokenizeError::UnexpectedEof(_) => write!(f, "unexpected end of input"),
+            TokenizeError::CharNotRecognized(ch, _) => {
+                write!(f, "unexpected character '{ch}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokenizeError {}
+
+pub fn tokenize(input: &str) -> Result<Vec<(Token, Span)>, TokenizeError> {
+    let mut lexer = Lexer::new();
     let mut tokens = Vec::new();
 
-    while index < chars.len() {
-        let token = make_token(&chars, &mut index)?;
-        tokens.push(token);
-        index += 1;
+    while lexer.position < input.len() {
+        tokens.push(lexer.next_token(input)?);
     }
 
     Ok(tokens)
 }
 
-fn make_token(chars: &[char], index: &mut usize) -> Result<Token, TokenizeError> {
-    let mut ch = chars[*index];
+/// A pull-based tokenizer that borrows the input `&str` instead of
+/// collecting it into a `Vec<char>`, so callers can consume one token at a
+/// time without allocating for the whole document up front.
+pub struct Lexer {
+    position: usize,
+}
 
-    while ch.is_ascii_whitespace() {
-        *index += 1;
-        if *index >= chars.len() {
-            return Err(TokenizeError::UnexpectedEof);
-        }
-        ch = chars[*index];
+impl Default for Lexer {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    let token = match ch {
-        '{' => Token::LeftBrace,
-        '}' => Token::RightBrace,
-        '[' => Token::LeftBracket,
-        ']' => Token::RightBracket,
-        ',' => Token::Comma,
-        ':' => Token::Colon,
+impl Lexer {
+    pub fn new() -> Self {
+        Self { position: 0 }
+    }
 
-        'n' => tokenize_null(chars, index)?,
-        'f' => tokenize_false(chars, index)?,
-        't' => tokenize_true(chars, index)?,
+    pub fn next_token(&mut self, input: &str) -> Result<(Token, Span), TokenizeError> {
+        let mut chars = input[self.position..].char_indices();
+
+        let (rel_offset, ch) = loop {
+            match chars.next() {
+                Some((_, c)) if c.is_ascii_whitespace() => continue,
+                Some(pair) => break pair,
+                None => {
+                    let eof = input.len();
+                    return Err(TokenizeError::UnexpectedEof(Span {
+                        start: eof,
+                        end: eof,
+                    }));
+                }
+            }
+        };
 
-        c if c.is_ascii_digit() || c == '-' => tokenize_float(chars, index)?,
+        self.position += rel_offset;
+        let start = self.position;
 
-        '"' => tokenize_string(chars, index)?,
+        let token = match ch {
+            '{' => {
+                self.position += 1;
+                Token::LeftBrace
+            }
+            '}' => {
+                self.position += 1;
+                Token::RightBrace
+            }
+            '[' => {
+                self.position += 1;
+                Token::LeftBracket
+            }
+            ']' => {
+                self.position += 1;
+                Token::RightBracket
+            }
+            ',' => {
+                self.position += 1;
+                Token::Comma
+            }
+            ':' => {
+                self.position += 1;
+                Token::Colon
+            }
 
-        ch => return Err(TokenizeError::CharNotRecognized(ch)),
-    };
+            'n' => self.tokenize_null(input)?,
+            'f' => self.tokenize_false(input)?,
+            't' => self.tokenize_true(input)?,
 
-    Ok(token)
-}
+            c if c.is_ascii_digit() || c == '-' => self.tokenize_float(input)?,
 
-fn tokenize_null(chars: &[char], index: &mut usize) -> Result<Token, TokenizeError> {
-    for expected_char in "null".chars() {
-        if chars[*index] != expected_char {
-            return Err(TokenizeError::UnfinishedLiteralValue);
-        }
-        *index += 1;
-    }
+            '"' => self.tokenize_string(input)?,
 
-    *index -= 1;
-    Ok(Token::Null)
-}
+            _ => {
+                let end = start + ch.len_utf8();
+                return Err(TokenizeError::CharNotRecognized(ch, Span { start, end }));
+            }
+        };
 
-fn tokenize_false(chars: &[char], index: &mut usize) -> Result<Token, TokenizeError> {
-    for expected_char in "false".chars() {
-        if chars[*index] != expected_char {
-            return Err(TokenizeError::UnfinishedLiteralValue);
-        }
-        *index += 1;
+        let end = self.position;
+        Ok((token, Span { start, end }))
     }
 
-    *index -= 1;
-    Ok(Token::False)
-}
-
-fn tokenize_true(chars: &[char], index: &mut usize) -> Result<Token, TokenizeError> {
-    for expected_char in "true".chars() {
-        if chars[*index] != expected_char {
-            return Err(TokenizeError::UnfinishedLiteralValue);
-        }
-        *index += 1;
+    fn tokenize_null(&mut self, input: &str) -> Result<Token, TokenizeError> {
+        let start = self.position;
+        self.expect_literal(input, "null", start)?;
+        Ok(Token::Null)
     }
 
-    *index -= 1;
-    Ok(Token::True)
-}
-
-fn tokenize_float(chars: &[char], index: &mut usize) -> Result<Token, TokenizeError> {
-    let mut unparsed_num = String::new();
-    let mut has_decimal = false;
-    let mut has_exp = false;
-    let mut has_sign_after_exp = false;
+    fn tokenize_false(&mut self, input: &str) -> Result<Token, TokenizeError> {
+        let start = self.position;
+        self.expect_literal(input, "false", start)?;
+        Ok(Token::False)
+    }
 
-    if chars[*index] == '-' {
-        unparsed_num.push('-');
-        *index += 1;
+    fn tokenize_true(&mut self, input: &str) -> Result<Token, TokenizeError> {
+        let start = self.position;
+        self.expect_literal(input, "true", start)?;
+        Ok(Token::True)
     }
 
-    while *index < chars.len() {
-        let ch = chars[*index];
-        match ch {
-            c if c.is_ascii_digit() => unparsed_num.push(c),
-            c if c == '.' && !has_decimal => {
-                unparsed_num.push('.');
-                has_decimal = true;
+    fn expect_literal(
+        &mut self,
+        input: &str,
+        literal: &str,
+        start: usize,
+    ) -> Result<(), TokenizeError> {
+        for expected_char in literal.chars() {
+            match input[self.position..].chars().next() {
+                Some(c) if c == expected_char => self.position += c.len_utf8(),
+                _ => {
+                    let end = self.position;
+                    return Err(TokenizeError::UnfinishedLiteralValue(Span { start, end }));
+                }
             }
+        }
+        Ok(())
+    }
 
-            c if c == 'e' || c == 'E' && !has_exp => {
-                unparsed_num.push(c);
-                has_exp = true;
-            }
-            c if c == '-' || c == '+' && has_exp && !has_sign_after_exp => {
-                unparsed_num.push(c);
-                has_sign_after_exp = true;
+    fn tokenize_float(&mut self, input: &str) -> Result<Token, TokenizeError> {
+        let start = self.position;
+        let mut negative = false;
+        let mut digits: i128 = 0;
+        let mut saw_digit = false;
+        let mut frac_digits: i32 = 0;
+        let mut has_decimal = false;
+        let mut has_exp = false;
+        let mut has_sign_after_exp = false;
+        let mut exp_negative = false;
+        let mut exp_value: i32 = 0;
+        let mut overflowed = false;
+
+        let mut chars = input[start..].char_indices().peekable();
+
+        if let Some(&(_, '-')) = chars.peek() {
+            negative = true;
+            chars.next();
+        }
+
+        loop {
+            match chars.peek() {
+                Some(&(_, c)) if c.is_ascii_digit() => {
+                    let digit = c.to_digit(10).unwrap() as i128;
+                    if has_exp {
+                        match exp_value
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(digit as i32))
+                        {
+                            Some(v) => exp_value = v,
+                            None => overflowed = true,
+                        }
+                    } else {
+                        match digits.checked_mul(10).and_then(|v| v.checked_add(digit)) {
+                            Some(v) => digits = v,
+                            None => overflowed = true,
+                        }
+                        saw_digit = true;
+                        if has_decimal {
+                            frac_digits += 1;
+                        }
+                    }
+                    chars.next();
+                }
+                Some(&(_, '.')) if !has_decimal && !has_exp => {
+                    has_decimal = true;
+                    chars.next();
+                }
+                Some(&(_, c)) if (c == 'e' || c == 'E') && !has_exp => {
+                    has_exp = true;
+                    chars.next();
+                }
+                Some(&(_, c)) if (c == '-' || c == '+') && has_exp && !has_sign_after_exp => {
+                    has_sign_after_exp = true;
+                    exp_negative = c == '-';
+                    chars.next();
+                }
+                _ => break,
             }
-            _ => break,
         }
 
-        *index += 1;
-    }
+        let end = match chars.peek() {
+            Some(&(rel_offset, _)) => start + rel_offset,
+            None => input.len(),
+        };
 
-    unparsed_num
-        .parse()
-        .map(Token::Number)
-        .map_err(|e| TokenizeError::ParseNumberError(e))
-}
+        self.position = end;
 
-fn tokenize_string(chars: &[char], index: &mut usize) -> Result<Token, TokenizeError> {
-    let mut string = String::new();
-    let mut is_escape = false;
+        if !saw_digit || overflowed {
+            return Err(TokenizeError::InvalidNumber(Span { start, end }));
+        }
 
-    loop {
-        *index += 1;
+        let exponent = frac_digits - if exp_negative { -exp_value } else { exp_value };
 
-        if *index >= chars.len() {
-            return Err(TokenizeError::UnclosedQuotes);
-        }
-        let ch = chars[*index];
-        match ch {
-            '"' if !is_escape => break,
-            '\\' if !is_escape => is_escape = true,
-            _ => is_escape = false,
-        }
-        string.push(ch);
+        Ok(Token::Number(Number::new(negative, digits, exponent)))
     }
 
-    Ok(Token::String(string))
+    fn tokenize_string(&mut self, input: &str) -> Result<Token, TokenizeError> {
+        let start = self.position;
+        let mut string = String::new();
+        let mut is_escape = false;
+
+        let mut chars = input[start..].char_indices();
+        chars.next(); // the opening quote
+
+        loop {
+            let (rel_offset, ch) = match chars.next() {
+                Some(pair) => pair,
+                None => {
+                    let end = input.len();
+                    return Err(TokenizeError::UnclosedQuotes(Span { start, end }));
+                }
+            };
+
+            match ch {
+                '"' if !is_escape => {
+                    self.position = start + rel_offset + ch.len_utf8();
+                    return Ok(Token::String(string));
+                }
+                '\\' if !is_escape => is_escape = true,
+                _ => is_escape = false,
+            }
+            string.push(ch);
+        }
+    }
 }
 
-
-
 #[cfg(test)]
 impl Token {
     pub fn string(input: &str) -> Self {
@@ -183,14 +310,19 @@ impl Token {
 
 #[cfg(test)]
 mod tests {
-    use crate::tokenize::TokenizeError;
+    use crate::number::Number;
+    use crate::tokenize::{Span, TokenizeError};
 
     use super::{tokenize, Token};
 
+    fn span(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
     #[test]
     fn just_comma() {
-        let input = String::from(",");
-        let expected = [Token::Comma];
+        let input = ",";
+        let expected = [(Token::Comma, span(0, 1))];
 
         let actual = tokenize(input).unwrap();
 
@@ -199,14 +331,14 @@ mod tests {
 
     #[test]
     fn all_punctuation() {
-        let input = String::from("[{]},:");
+        let input = "[{]},:";
         let expected = [
-            Token::LeftBracket,
-            Token::LeftBrace,
-            Token::RightBracket,
-            Token::RightBrace,
-            Token::Comma,
-            Token::Colon,
+            (Token::LeftBracket, span(0, 1)),
+            (Token::LeftBrace, span(1, 2)),
+            (Token::RightBracket, span(2, 3)),
+            (Token::RightBrace, span(3, 4)),
+            (Token::Comma, span(4, 5)),
+            (Token::Colon, span(5, 6)),
         ];
 
         let actual = tokenize(input).unwrap();
@@ -216,8 +348,8 @@ mod tests {
 
     #[test]
     fn just_null() {
-        let input = String::from("null");
-        let expected = [Token::Null];
+        let input = "null";
+        let expected = [(Token::Null, span(0, 4))];
 
         let actual = tokenize(input).unwrap();
 
@@ -226,8 +358,8 @@ mod tests {
 
     #[test]
     fn just_false() {
-        let input = String::from("false");
-        let expected = [Token::False];
+        let input = "false";
+        let expected = [(Token::False, span(0, 5))];
 
         let actual = tokenize(input).unwrap();
 
@@ -236,8 +368,8 @@ mod tests {
 
     #[test]
     fn just_true() {
-        let input = String::from("true");
-        let expected = [Token::True];
+        let input = "true";
+        let expected = [(Token::True, span(0, 4))];
 
         let actual = tokenize(input).unwrap();
 
@@ -246,8 +378,8 @@ mod tests {
 
     #[test]
     fn true_comma() {
-        let input = String::from("true,");
-        let expected = [Token::True, Token::Comma];
+        let input = "true,";
+        let expected = [(Token::True, span(0, 4)), (Token::Comma, span(4, 5))];
 
         let actual = tokenize(input).unwrap();
 
@@ -256,8 +388,8 @@ mod tests {
 
     #[test]
     fn integer() {
-        let input = String::from("123");
-        let expected = [Token::Number(123.0)];
+        let input = "123";
+        let expected = [(Token::Number(Number::new(false, 123, 0)), span(0, 3))];
 
         let actual = tokenize(input).unwrap();
 
@@ -266,8 +398,8 @@ mod tests {
 
     #[test]
     fn float() {
-        let input = String::from("123.456");
-        let expected = [Token::Number(123.456)];
+        let input = "123.456";
+        let expected = [(Token::Number(Number::new(false, 123456, 3)), span(0, 7))];
 
         let actual = tokenize(input).unwrap();
 
@@ -276,8 +408,8 @@ mod tests {
 
     #[test]
     fn negative_integer() {
-        let input = String::from("-123");
-        let expected = [Token::Number(-123.0)];
+        let input = "-123";
+        let expected = [(Token::Number(Number::new(true, 123, 0)), span(0, 4))];
 
         let actual = tokenize(input).unwrap();
 
@@ -286,8 +418,8 @@ mod tests {
 
     #[test]
     fn negative_float() {
-        let input = String::from("-123.456");
-        let expected = [Token::Number(-123.456)];
+        let input = "-123.456";
+        let expected = [(Token::Number(Number::new(true, 123456, 3)), span(0, 8))];
 
         let actual = tokenize(input).unwrap();
 
@@ -296,28 +428,67 @@ mod tests {
 
     #[test]
     fn negative_float_with_exponent() {
-        let input = String::from("-123.456e+2");
-        let expected = [Token::Number(-123.456e2)];
+        let input = "-123.456e+2";
+        let expected = [(Token::Number(Number::new(true, 123456, 1)), span(0, 11))];
 
         let actual = tokenize(input).unwrap();
 
         assert_eq!(actual, expected);
+        if let Token::Number(number) = &actual[0].0 {
+            assert_eq!(number.as_f64(), -123.456e2);
+        }
     }
 
     #[test]
     fn negative_float_with_exponent_and_sign() {
-        let input = String::from("-123.456e-2");
-        let expected = [Token::Number(-123.456e-2)];
+        let input = "-123.456e-2";
+        let expected = [(Token::Number(Number::new(true, 123456, 5)), span(0, 11))];
 
         let actual = tokenize(input).unwrap();
 
+        assert_eq!(actual, expected);
+        if let Token::Number(number) = &actual[0].0 {
+            assert_eq!(number.as_f64(), -123.456e-2);
+        }
+    }
+
+    #[test]
+    fn huge_integer_preserves_precision() {
+        let input = "12345678901234567890";
+        let expected = [(
+            Token::Number(Number::new(false, 12345678901234567890, 0)),
+            span(0, 20),
+        )];
+
+        let actual = tokenize(input).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn number_wider_than_i128_is_rejected() {
+        let input = "9".repeat(50);
+        let expected = Err(TokenizeError::InvalidNumber(span(0, 50)));
+
+        let actual = tokenize(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn number_with_huge_exponent_is_rejected() {
+        let input = "1e999999999999999999999";
+        let expected = Err(TokenizeError::InvalidNumber(span(0, 23)));
+
+        let actual = tokenize(input);
+
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn just_ken() {
-        let input = String::from("\"ken\"");
-        let expected = [Token::string("ken")];
+        let input = "\"ken\"";
+        let expected = [(Token::string("ken"), span(0, 5))];
 
         let actual = tokenize(input).unwrap();
 
@@ -326,8 +497,8 @@ mod tests {
 
     #[test]
     fn unclosed_string() {
-        let input = String::from("\"unclosed");
-        let expected = Err(TokenizeError::UnclosedQuotes);
+        let input = "\"unclosed";
+        let expected = Err(TokenizeError::UnclosedQuotes(span(0, 9)));
 
         let actual = tokenize(input);
 
@@ -336,8 +507,8 @@ mod tests {
 
     #[test]
     fn escaped_quote() {
-        let input = String::from(r#""the \" is OK""#);
-        let expected = [Token::string(r#"the \" is OK"#)];
+        let input = r#""the \" is OK""#;
+        let expected = [(Token::string(r#"the \" is OK"#), span(0, 14))];
 
         let actual = tokenize(input).unwrap();
 
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 
+use crate::number::Number;
+
 #[derive(Debug, PartialEq)]
 pub enum Value {
     Null,
     Boolean(bool),
     String(String),
-    Number(f64),
+    Number(Number),
     Array(Vec<Value>),
     Object(HashMap<String, Value>)
 }
\ No newline at end of file